@@ -8,29 +8,33 @@ use core::iter::{DoubleEndedIterator, FusedIterator};
 #[derive(Debug, PartialEq)]
 pub struct Drain<'a, T, const N: usize> {
   inner: &'a mut ArrayVec<T, { N }>,
-  /// The index of the first item being removed. 
+  /// The index of the first item being removed.
   drain_range_start: usize,
-  /// The index of the first item after the drained range. 
+  /// The index of the first item after the drained range.
   tail_start: usize,
-  tail_length: usize, 
-  /// The front of the remaining drained range. 
+  tail_length: usize,
+  /// The front of the remaining drained range.
   head: *mut T,
-  /// One after the last item in the range being drained. 
-  tail: *mut T, 
+  /// One after the last item in the range being drained.
+  tail: *mut T,
+  /// The number of items still to be yielded, tracked separately from
+  /// `head`/`tail` so `len()` doesn't need to divide `(tail - head)` by
+  /// `size_of::<T>()` (which breaks for zero-sized types).
+  remaining: usize,
 }
 
 impl<'a, T, const N: usize> Drain<'a, T, { N }> {
   pub(crate) fn with_range(vector: &'a mut ArrayVec<T, { N }>, range: Range<usize>) -> Self {
     debug_assert!(range.start <= range.end, "The range start must be before end");
     debug_assert!(range.end <= vector.len(), "The range is out of bounds");
-    debug_assert!(core::mem::size_of::<T>() != 0, "We can't deal with zero-size types");
 
     unsafe {
       let head = vector.as_mut_ptr().add(range.start);
       let tail = vector.as_mut_ptr().add(range.end);
-      let tail_length = vector.len() - (range.end - range.start);
+      let remaining = range.end - range.start;
+      let tail_length = vector.len() - range.end;
 
-      // prevent a leaked Drain from letting users read from uninitialized memory 
+      // prevent a leaked Drain from letting users read from uninitialized memory
       vector.set_len(range.start);
 
       Drain {
@@ -40,6 +44,7 @@ impl<'a, T, const N: usize> Drain<'a, T, { N }> {
         tail_length,
         head,
         tail,
+        remaining,
       }
     }
   }
@@ -49,13 +54,14 @@ impl<'a, T, const N: usize> Iterator for Drain<'a, T, { N }> {
   type Item = T;
 
   fn next(&mut self) -> Option<Self::Item> {
-    if self.head == self.tail {
+    if self.remaining == 0 {
       return None;
     }
 
     unsafe {
       let item = self.head.read();
       self.head = self.head.add(1);
+      self.remaining -= 1;
       Some(item)
     }
   }
@@ -67,28 +73,21 @@ impl<'a, T, const N: usize> Iterator for Drain<'a, T, { N }> {
 
 impl<'a, T, const N: usize> DoubleEndedIterator for Drain<'a, T, { N }> {
   fn next_back(&mut self) -> Option<Self::Item> {
-    if self.head == self.tail {
+    if self.remaining == 0 {
       return None;
     }
 
     unsafe {
       self.tail = self.tail.sub(1);
       let item = self.tail.read();
+      self.remaining -= 1;
       Some(item)
     }
   }
 }
 
 impl<'a, T, const N: usize> ExactSizeIterator for Drain<'a, T, { N }> {
-  fn len(&self) -> usize {
-    let size = mem::size_of::<T>();
-    assert!(0 < size && size <= isize::max_value() as usize);
-
-    let difference = (self.tail as isize) - (self.head as isize);
-    debug_assert!(difference >= 0, "Tail should always be after head");
-
-    difference as usize / size
-  }
+  fn len(&self) -> usize { self.remaining }
 }
 
 impl<'a, T, const N: usize> FusedIterator for Drain<'a, T, { N }> {}
@@ -0,0 +1,155 @@
+use crate::ArrayVec;
+
+use core::iter::{DoubleEndedIterator, FusedIterator};
+use core::mem::ManuallyDrop;
+use core::ptr;
+use core::slice;
+
+/// An iterator that moves out of an [`ArrayVec`], consuming it element by
+/// element.
+///
+/// Tracks `head`/`tail` as indices rather than pointers, so its length is
+/// always `tail - head` with no division by `size_of::<T>()` involved —
+/// this keeps it correct for zero-sized types too.
+///
+/// ```rust
+/// use const_arrayvec::ArrayVec;
+/// let mut vector: ArrayVec<(), 8> = ArrayVec::new();
+///
+/// for _ in 0..8 {
+///   vector.push(());
+/// }
+///
+/// assert_eq!(vector.into_iter().count(), 8);
+/// ```
+///
+/// It also moves non-zero-sized items out by value, and supports pulling
+/// from both ends:
+///
+/// ```rust
+/// use const_arrayvec::ArrayVec;
+/// let mut vector: ArrayVec<String, 4> = ArrayVec::new();
+///
+/// vector.push(String::from("a"));
+/// vector.push(String::from("b"));
+/// vector.push(String::from("c"));
+///
+/// let mut iter = vector.into_iter();
+///
+/// assert_eq!(iter.next(), Some(String::from("a")));
+/// assert_eq!(iter.next_back(), Some(String::from("c")));
+/// assert_eq!(iter.next(), Some(String::from("b")));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// Dropping an `IntoIter` before it's fully consumed still destroys
+/// whatever elements were left over:
+///
+/// ```rust
+/// use const_arrayvec::ArrayVec;
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// struct CountsDrops(Rc<Cell<u32>>);
+///
+/// impl Drop for CountsDrops {
+///   fn drop(&mut self) { self.0.set(self.0.get() + 1); }
+/// }
+///
+/// let drops = Rc::new(Cell::new(0));
+/// let mut vector: ArrayVec<CountsDrops, 4> = ArrayVec::new();
+///
+/// for _ in 0..4 {
+///   vector.push(CountsDrops(drops.clone()));
+/// }
+///
+/// let mut iter = vector.into_iter();
+/// iter.next();
+/// iter.next_back();
+///
+/// assert_eq!(drops.get(), 2);
+///
+/// drop(iter);
+///
+/// assert_eq!(drops.get(), 4);
+/// ```
+pub struct IntoIter<T, const N: usize> {
+  /// Wrapped in `ManuallyDrop` so the source vector's `Drop` impl doesn't
+  /// run (and double-drop the elements we're handing out below); our own
+  /// `Drop` impl takes care of destroying whatever's left in `head..tail`.
+  inner: ManuallyDrop<ArrayVec<T, { N }>>,
+  head: usize,
+  tail: usize,
+}
+
+impl<T, const N: usize> IntoIter<T, { N }> {
+  pub(crate) fn new(vector: ArrayVec<T, { N }>) -> Self {
+    let tail = vector.len();
+
+    IntoIter {
+      inner: ManuallyDrop::new(vector),
+      head: 0,
+      tail,
+    }
+  }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, { N }> {
+  type Item = T;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.head == self.tail {
+      return None;
+    }
+
+    unsafe {
+      let item = ptr::read(self.inner.as_ptr().add(self.head));
+      self.head += 1;
+      Some(item)
+    }
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (self.len(), Some(self.len()))
+  }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, { N }> {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    if self.head == self.tail {
+      return None;
+    }
+
+    unsafe {
+      self.tail -= 1;
+      Some(ptr::read(self.inner.as_ptr().add(self.tail)))
+    }
+  }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, { N }> {
+  fn len(&self) -> usize { self.tail - self.head }
+}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, { N }> {}
+
+impl<T, const N: usize> Drop for IntoIter<T, { N }> {
+  fn drop(&mut self) {
+    unsafe {
+      // drop whatever elements are still live; anything outside
+      // `head..tail` has already been read out by `next`/`next_back`
+      let remaining = slice::from_raw_parts_mut(
+        self.inner.as_mut_ptr().add(self.head),
+        self.tail - self.head,
+      );
+      ptr::drop_in_place(remaining);
+    }
+  }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVec<T, { N }> {
+  type Item = T;
+  type IntoIter = IntoIter<T, { N }>;
+
+  fn into_iter(self) -> Self::IntoIter { IntoIter::new(self) }
+}
@@ -4,13 +4,30 @@
 #![no_std]
 #![feature(const_generics)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod drain;
+mod into_iter;
+mod raw;
+#[cfg(feature = "serde")]
+mod serde;
+mod string;
+
+pub use drain::Drain;
+pub use into_iter::IntoIter;
+pub use string::ArrayString;
+
+use raw::RawArrayVec;
+
 use core::ptr;
 use core::slice;
 use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
-use core::mem::{self, MaybeUninit};
+use core::iter::{Extend, FromIterator, IntoIterator};
+use core::mem;
 use core::fmt::{self, Debug, Display, Formatter};
-use core::ops::{Deref, DerefMut, Index, IndexMut};
+use core::ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds};
 
 macro_rules! out_of_bounds {
   ($method:expr, $index:expr, $len:expr) => {
@@ -35,37 +52,42 @@ impl<T> Display for CapacityError<T> {
 }
 
 pub struct ArrayVec<T, const N: usize> {
-  items: [MaybeUninit<T>; N],
-  length: usize,
+  raw: RawArrayVec<T, { N }>,
+}
+
+/// Restores an [`ArrayVec`]'s length to whatever's been written to
+/// `0..write` so far, even if [`ArrayVec::retain`]'s predicate (or a
+/// dropped item's destructor) panics partway through.
+struct RetainGuard<'a, T, const N: usize> {
+  vec: &'a mut ArrayVec<T, { N }>,
+  read: usize,
+  write: usize,
+}
+
+impl<'a, T, const N: usize> Drop for RetainGuard<'a, T, { N }> {
+  fn drop(&mut self) {
+    unsafe { self.vec.set_len(self.write); }
+  }
 }
 
 impl<T, const N: usize> ArrayVec<T, { N }> {
   pub fn new() -> Self {
-    unsafe {
-      ArrayVec {
-        // this is safe because we've asked for a big block of
-        // uninitialized memory which will be treated as an 
-        // array of uninitialized items, which is perfectly 
-        // valid for [MaybeUninit<_>; N]
-        items: MaybeUninit::uninit().assume_init(),
-        length: 0,
-      }
-    }
+    ArrayVec { raw: RawArrayVec::new() }
   }
 
-  pub const fn len(&self) -> usize { self.length }  
+  pub const fn len(&self) -> usize { self.raw.len() }
 
   pub const fn is_empty(&self) -> bool { self.len() == 0 }
 
-  pub const fn capacity(&self) -> usize { N }
+  pub const fn capacity(&self) -> usize { self.raw.capacity() }
 
   pub const fn is_full(&self) -> bool { self.len() == self.capacity() }
 
   pub const fn remaining_capacity(&self) -> usize { self.capacity() - self.len() }
 
-  pub fn as_ptr(&self) -> *const T { self.items.as_ptr() as *const T }
+  pub fn as_ptr(&self) -> *const T { self.raw.as_ptr() }
 
-  pub fn as_mut_ptr(&mut self) -> *mut T { self.items.as_mut_ptr() as *mut T }
+  pub fn as_mut_ptr(&mut self) -> *mut T { self.raw.as_mut_ptr() }
 
   pub fn as_slice(&self) -> &[T] { self.deref() }
 
@@ -79,25 +101,17 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
   /// 
   /// This method uses *debug assertions* to detect overflows in debug builds. 
   pub unsafe fn push_unchecked(&mut self, item: T) {
-    debug_assert!(!self.is_full());
-    let len = self.len();
-
-    // index into the underlying array using pointer arithmetic and write 
-    // the item to the correct spot 
-    self.as_mut_ptr().add(len).write(item);
-
-    self.set_len(len + 1);
+    self.raw.push_unchecked(item);
   }
 
-  /// Set the vector's length without dropping or moving out elements. 
-  /// 
+  /// Set the vector's length without dropping or moving out elements.
+  ///
   /// # Safety
-  /// 
-  /// This method is unsafe because it changes the number of valid elements 
-  /// the vector thinks it contains, without adding or removing any elements. 
+  ///
+  /// This method is unsafe because it changes the number of valid elements
+  /// the vector thinks it contains, without adding or removing any elements.
   pub unsafe fn set_len(&mut self, new_length: usize) {
-    debug_assert!(new_length <= self.capacity());
-    self.length = new_length;
+    self.raw.set_len(new_length);
   }
 
   /// Add an item to the end of the vector. 
@@ -233,6 +247,134 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
     }
   }
 
+  /// Remove the item at `index`, replacing it with the last item in the
+  /// vector.
+  ///
+  /// This doesn't preserve ordering, but runs in O(1) since it never has
+  /// to shift the remaining items.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is out of bounds.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use const_arrayvec::ArrayVec;
+  /// let mut vector: ArrayVec<u32, 5> = ArrayVec::new();
+  ///
+  /// vector.push(10);
+  /// vector.push(20);
+  /// vector.push(30);
+  /// vector.push(40);
+  ///
+  /// assert_eq!(vector.swap_remove(0), 10);
+  /// assert_eq!(vector.as_slice(), &[40, 20, 30]);
+  /// ```
+  pub fn swap_remove(&mut self, index: usize) -> T {
+    let len = self.len();
+
+    if index >= len {
+      out_of_bounds!("swap_remove", index, len);
+    }
+
+    unsafe {
+      // grab the last item, then swap it in over `index`; if `index` is
+      // itself the last item this is a harmless read-then-write-back
+      let last = ptr::read(self.as_ptr().add(len - 1));
+      let item = ptr::replace(self.as_mut_ptr().add(index), last);
+      self.set_len(len - 1);
+      item
+    }
+  }
+
+  /// Remove and return the item at `index`, shifting all items after it
+  /// left by one.
+  ///
+  /// The inverse of [`try_insert`](ArrayVec::try_insert).
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is out of bounds.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use const_arrayvec::ArrayVec;
+  /// let mut vector: ArrayVec<u32, 5> = ArrayVec::new();
+  ///
+  /// vector.push(10);
+  /// vector.push(20);
+  /// vector.push(30);
+  ///
+  /// assert_eq!(vector.remove(0), 10);
+  /// assert_eq!(vector.as_slice(), &[20, 30]);
+  /// ```
+  pub fn remove(&mut self, index: usize) -> T {
+    let len = self.len();
+
+    if index >= len {
+      out_of_bounds!("remove", index, len);
+    }
+
+    unsafe {
+      let p = self.as_mut_ptr().add(index);
+      let item = ptr::read(p);
+      // shift everything after `index` down to close the gap
+      ptr::copy(p.offset(1), p, len - index - 1);
+      self.set_len(len - 1);
+      item
+    }
+  }
+
+  /// Keep only the items for which `f` returns `true`, removing (and
+  /// dropping) the rest, preserving the relative order of the items kept.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use const_arrayvec::ArrayVec;
+  /// let mut vector: ArrayVec<u32, 5> = ArrayVec::new();
+  ///
+  /// for i in 1..=5 {
+  ///   vector.push(i);
+  /// }
+  ///
+  /// vector.retain(|&item| item % 2 == 0);
+  ///
+  /// assert_eq!(vector.as_slice(), &[2, 4]);
+  /// ```
+  pub fn retain<F>(&mut self, mut f: F)
+    where F: FnMut(&T) -> bool
+  {
+    let original_len = self.len();
+
+    // pretend the vector is empty so a panic partway through (either in
+    // `f` or in a dropped item's destructor) can't leave anyone observing
+    // items that have already been moved or destroyed; `RetainGuard`
+    // restores the length to whatever's actually been kept so far
+    unsafe { self.set_len(0); }
+
+    let mut guard = RetainGuard { vec: self, read: 0, write: 0 };
+
+    while guard.read < original_len {
+      unsafe {
+        let p = guard.vec.as_mut_ptr().add(guard.read);
+
+        if f(&*p) {
+          if guard.read != guard.write {
+            ptr::copy(p, guard.vec.as_mut_ptr().add(guard.write), 1);
+          }
+          guard.write += 1;
+        } else {
+          ptr::drop_in_place(p);
+        }
+      }
+
+      guard.read += 1;
+    }
+  }
+
   pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), CapacityError<()>>
     where T: Copy
   {
@@ -253,6 +395,78 @@ impl<T, const N: usize> ArrayVec<T, { N }> {
 
     Ok(())
   }
+
+  /// Extend the vector with the contents of an iterator, stopping at (and
+  /// returning) the first item that doesn't fit.
+  ///
+  /// Items already pushed before capacity ran out remain in the vector.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use const_arrayvec::{ArrayVec, CapacityError};
+  /// let mut vector: ArrayVec<u32, 3> = ArrayVec::new();
+  ///
+  /// assert_eq!(vector.try_extend(0..2), Ok(()));
+  /// assert_eq!(vector.try_extend(2..10), Err(CapacityError(3)));
+  ///
+  /// // the items that did fit are still there
+  /// assert_eq!(vector.as_slice(), &[0, 1, 2]);
+  /// ```
+  pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), CapacityError<T>> {
+    for item in iter {
+      self.try_push(item)?;
+    }
+
+    Ok(())
+  }
+
+  /// Remove the items in `range`, returning them as an iterator.
+  ///
+  /// Dropping the iterator removes the items even if it isn't fully
+  /// consumed, and the removed range is shifted out of the vector
+  /// regardless of whether the caller iterates over it.
+  ///
+  /// This works just as well for zero-sized types as it does for anything
+  /// else:
+  ///
+  /// ```rust
+  /// use const_arrayvec::ArrayVec;
+  /// let mut vector: ArrayVec<(), 8> = ArrayVec::new();
+  ///
+  /// for _ in 0..8 {
+  ///   vector.push(());
+  /// }
+  ///
+  /// assert_eq!(vector.drain(2..5).count(), 3);
+  /// assert_eq!(vector.len(), 5);
+  /// assert_eq!(vector.remaining_capacity(), 3);
+  /// ```
+  pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, { N }>
+    where R: RangeBounds<usize>
+  {
+    let len = self.len();
+
+    let start = match range.start_bound() {
+      Bound::Included(&i) => i,
+      Bound::Excluded(&i) => i + 1,
+      Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+      Bound::Included(&i) => i + 1,
+      Bound::Excluded(&i) => i,
+      Bound::Unbounded => len,
+    };
+
+    if start > end {
+      out_of_bounds!("drain", start, len);
+    }
+    if end > len {
+      out_of_bounds!("drain", end, len);
+    }
+
+    Drain::with_range(self, start..end)
+  }
 }
 
 impl<T, const N: usize> Drop for ArrayVec<T, { N }> {
@@ -372,4 +586,78 @@ impl<T, const N: usize> From<[T; N]> for ArrayVec<T, { N }> {
 
     vec
   }
+}
+
+/// Build an `ArrayVec` by collecting an iterator.
+///
+/// # Panics
+///
+/// Panics if the iterator yields more than `N` items.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_arrayvec::ArrayVec;
+/// let vector: ArrayVec<u32, 4> = (0..4).collect();
+///
+/// assert_eq!(vector.as_slice(), &[0, 1, 2, 3]);
+/// ```
+///
+/// Overflowing the capacity panics, the same way [`push`](ArrayVec::push) does:
+///
+/// ```rust should_panic
+/// use const_arrayvec::ArrayVec;
+/// let _vector: ArrayVec<u32, 2> = (0..3).collect();
+/// ```
+impl<T, const N: usize> FromIterator<T> for ArrayVec<T, { N }> {
+  fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+    let mut vec = ArrayVec::new();
+    vec.extend(iter);
+    vec
+  }
+}
+
+/// Pour an iterator's items into the vector, growing it up to `N` items.
+///
+/// # Panics
+///
+/// Panics if there are more items than remaining capacity, the same way
+/// [`push`](ArrayVec::push) does.
+impl<T, const N: usize> Extend<T> for ArrayVec<T, { N }> {
+  fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+    for item in iter {
+      self.push(item);
+    }
+  }
+}
+
+/// Lets you `write!()` formatted output straight into an `ArrayVec<u8, N>`
+/// without allocating, returning `Err` instead of panicking if it overflows.
+impl<const N: usize> fmt::Write for ArrayVec<u8, { N }> {
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    self.try_extend_from_slice(s.as_bytes()).map_err(|_| fmt::Error)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> std::io::Write for ArrayVec<u8, { N }> {
+  fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+    // intentional deviation from "return WriteZero on overflow": a real
+    // io::Write sink is allowed to make partial progress, and write_all
+    // relies on that to loop over an over-long buffer instead of just
+    // failing it outright, so we write as much of `buf` as fits and only
+    // return WriteZero when we can't make any progress at all
+    let len = buf.len().min(self.remaining_capacity());
+
+    if len == 0 && !buf.is_empty() {
+      return Err(std::io::Error::from(std::io::ErrorKind::WriteZero));
+    }
+
+    self.try_extend_from_slice(&buf[..len])
+      .expect("len was clamped to the remaining capacity");
+
+    Ok(len)
+  }
+
+  fn flush(&mut self) -> std::io::Result<()> { Ok(()) }
 }
\ No newline at end of file
@@ -0,0 +1,63 @@
+use core::mem::MaybeUninit;
+
+/// The unsafe core shared by [`ArrayVec`](crate::ArrayVec) and
+/// [`ArrayString`](crate::ArrayString).
+///
+/// This is just the `[MaybeUninit<T>; N]` storage plus a `length`, with
+/// enough primitives (`push_unchecked`, `set_len`, `as_ptr`/`as_mut_ptr`) for
+/// the types built on top of it to implement their own capacity checks,
+/// `Drop` semantics, and public APIs.
+pub(crate) struct RawArrayVec<T, const N: usize> {
+  items: [MaybeUninit<T>; N],
+  length: usize,
+}
+
+impl<T, const N: usize> RawArrayVec<T, { N }> {
+  pub(crate) fn new() -> Self {
+    unsafe {
+      RawArrayVec {
+        // this is safe because we've asked for a big block of
+        // uninitialized memory which will be treated as an
+        // array of uninitialized items, which is perfectly
+        // valid for [MaybeUninit<_>; N]
+        items: MaybeUninit::uninit().assume_init(),
+        length: 0,
+      }
+    }
+  }
+
+  pub(crate) const fn len(&self) -> usize { self.length }
+
+  pub(crate) const fn capacity(&self) -> usize { N }
+
+  pub(crate) fn as_ptr(&self) -> *const T { self.items.as_ptr() as *const T }
+
+  pub(crate) fn as_mut_ptr(&mut self) -> *mut T { self.items.as_mut_ptr() as *mut T }
+
+  /// Add an item to the end of the buffer without checking capacity.
+  ///
+  /// # Safety
+  ///
+  /// It is up to the caller to ensure the buffer's capacity is suitably large.
+  pub(crate) unsafe fn push_unchecked(&mut self, item: T) {
+    debug_assert!(self.length < N);
+    let len = self.length;
+
+    // index into the underlying array using pointer arithmetic and write
+    // the item to the correct spot
+    self.as_mut_ptr().add(len).write(item);
+
+    self.set_len(len + 1);
+  }
+
+  /// Set the buffer's length without dropping or moving out elements.
+  ///
+  /// # Safety
+  ///
+  /// This method is unsafe because it changes the number of valid elements
+  /// the buffer thinks it contains, without adding or removing any elements.
+  pub(crate) unsafe fn set_len(&mut self, new_length: usize) {
+    debug_assert!(new_length <= self.capacity());
+    self.length = new_length;
+  }
+}
@@ -0,0 +1,48 @@
+use crate::ArrayVec;
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+impl<T: Serialize, const N: usize> Serialize for ArrayVec<T, { N }> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer
+  {
+    serializer.collect_seq(self.as_slice())
+  }
+}
+
+struct ArrayVecVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVecVisitor<T, { N }> {
+  type Value = ArrayVec<T, { N }>;
+
+  fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "no more than {} items", N)
+  }
+
+  fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where A: SeqAccess<'de>
+  {
+    let mut vector = ArrayVec::new();
+
+    while let Some(item) = seq.next_element()? {
+      // surface the capacity error as a deserialize error rather than
+      // panicking, so a too-long sequence fails cleanly
+      vector.try_push(item)
+        .map_err(|_| serde::de::Error::invalid_length(vector.len() + 1, &self))?;
+    }
+
+    Ok(vector)
+  }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for ArrayVec<T, { N }> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de>
+  {
+    deserializer.deserialize_seq(ArrayVecVisitor(PhantomData))
+  }
+}
@@ -0,0 +1,152 @@
+use crate::raw::RawArrayVec;
+use crate::CapacityError;
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::Deref;
+use core::ptr;
+use core::str;
+
+/// A fixed-capacity string backed by a stack-allocated buffer of `N` bytes.
+///
+/// `ArrayString` shares its backing storage with [`ArrayVec`](crate::ArrayVec):
+/// both are built on top of [`RawArrayVec`], so the byte buffer here behaves
+/// exactly like an `ArrayVec<u8, N>` that maintains the extra invariant of
+/// always containing valid UTF-8.
+pub struct ArrayString<const N: usize> {
+  raw: RawArrayVec<u8, { N }>,
+}
+
+impl<const N: usize> ArrayString<{ N }> {
+  pub fn new() -> Self {
+    ArrayString { raw: RawArrayVec::new() }
+  }
+
+  pub const fn len(&self) -> usize { self.raw.len() }
+
+  pub const fn is_empty(&self) -> bool { self.len() == 0 }
+
+  pub const fn capacity(&self) -> usize { self.raw.capacity() }
+
+  pub const fn is_full(&self) -> bool { self.len() == self.capacity() }
+
+  pub const fn remaining_capacity(&self) -> usize { self.capacity() - self.len() }
+
+  pub fn as_str(&self) -> &str {
+    unsafe {
+      // safe because every byte we've written came from a `&str` or `char`,
+      // so the initialized prefix is always valid UTF-8
+      let bytes = core::slice::from_raw_parts(self.raw.as_ptr(), self.raw.len());
+      str::from_utf8_unchecked(bytes)
+    }
+  }
+
+  /// Append a `char` to the end of the string.
+  ///
+  /// # Panics
+  ///
+  /// The string must have enough room for the character's UTF-8 encoding.
+  pub fn push(&mut self, c: char) {
+    match self.try_push(c) {
+      Ok(_)  => {},
+      Err(e) => panic!("Push failed: {}", e),
+    }
+  }
+
+  /// Try to append a `char`, returning it if there wasn't enough room.
+  pub fn try_push(&mut self, c: char) -> Result<(), CapacityError<char>> {
+    let mut buf = [0; 4];
+    let encoded = c.encode_utf8(&mut buf);
+
+    match self.try_push_str(encoded) {
+      Ok(_) => Ok(()),
+      Err(_) => Err(CapacityError(c)),
+    }
+  }
+
+  /// Append a `&str` to the end of the string.
+  ///
+  /// # Panics
+  ///
+  /// The string must have enough room for all of `other`'s bytes.
+  pub fn push_str(&mut self, other: &str) {
+    match self.try_push_str(other) {
+      Ok(_)  => {},
+      Err(e) => panic!("Push failed: {}", e),
+    }
+  }
+
+  /// Try to append a `&str`, returning it if there wasn't enough room for
+  /// its UTF-8 encoding.
+  pub fn try_push_str<'a>(&mut self, other: &'a str) -> Result<(), CapacityError<&'a str>> {
+    if other.len() > self.remaining_capacity() {
+      return Err(CapacityError(other));
+    }
+
+    let len = self.raw.len();
+
+    unsafe {
+      // note: we have a mutable reference to self, so it's not possible
+      // for the two buffers to overlap
+      ptr::copy_nonoverlapping(other.as_ptr(), self.raw.as_mut_ptr().add(len), other.len());
+      self.raw.set_len(len + other.len());
+    }
+
+    Ok(())
+  }
+}
+
+impl<const N: usize> Deref for ArrayString<{ N }> {
+  type Target = str;
+
+  fn deref(&self) -> &Self::Target { self.as_str() }
+}
+
+impl<const N: usize> AsRef<str> for ArrayString<{ N }> {
+  fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl<const N: usize> Default for ArrayString<{ N }> {
+  fn default() -> Self { ArrayString::new() }
+}
+
+impl<const N: usize> Debug for ArrayString<{ N }> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { Debug::fmt(self.as_str(), f) }
+}
+
+impl<const N: usize> Display for ArrayString<{ N }> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { Display::fmt(self.as_str(), f) }
+}
+
+impl<const N: usize> PartialEq for ArrayString<{ N }> {
+  fn eq(&self, other: &Self) -> bool { self.as_str() == other.as_str() }
+}
+
+impl<const N: usize> Eq for ArrayString<{ N }> {}
+
+impl<const N: usize> PartialEq<str> for ArrayString<{ N }> {
+  fn eq(&self, other: &str) -> bool { self.as_str() == other }
+}
+
+impl<const N: usize> PartialEq<&str> for ArrayString<{ N }> {
+  fn eq(&self, other: &&str) -> bool { self.as_str() == *other }
+}
+
+/// Lets you `write!()` formatted output straight into an `ArrayString`
+/// without allocating, returning `Err` instead of panicking if it overflows.
+impl<const N: usize> fmt::Write for ArrayString<{ N }> {
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    self.try_push_str(s).map_err(|_| fmt::Error)
+  }
+}
+
+impl<const N: usize> Clone for ArrayString<{ N }> {
+  fn clone(&self) -> ArrayString<{ N }> {
+    let mut other: ArrayString<{ N }> = ArrayString::new();
+
+    // if it fits into the original, it'll fit into the clone
+    other.try_push_str(self.as_str())
+      .expect("the original already fit, so the clone has room too");
+
+    other
+  }
+}